@@ -3,20 +3,56 @@ use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::BufReader;
+use thiserror::Error;
+
+/// Errors returned while parsing a PCD file or extracting a field from it.
+#[derive(Debug, Error)]
+pub enum PcdError {
+    #[error("not a pcd file")]
+    NotAPcdFile,
+    #[error("unsupported DATA format: {0}")]
+    UnsupportedDataFormat(String),
+    #[error("malformed header line: {line}")]
+    MalformedHeaderLine { line: String },
+    #[error("malformed data line: {line}")]
+    MalformedDataLine { line: String },
+    #[error("bad field count: expected {expected}, got {got}")]
+    BadFieldCount { expected: usize, got: usize },
+    #[error("grid size mismatch: {width} * {height} != {num_points}")]
+    GridSizeMismatch {
+        width: usize,
+        height: usize,
+        num_points: usize,
+    },
+    #[error("field {field} is not of type {expected_type}{expected_size}")]
+    FieldTypeMismatch {
+        field: String,
+        expected_type: String,
+        expected_size: usize,
+    },
+    #[error("unknown field: {0}")]
+    UnknownField(String),
+    #[error("unsupported field type: {0}")]
+    UnsupportedFieldType(String),
+    #[error("failed to decompress binary_compressed data")]
+    Decompress,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
 
 /// PointCloud struct
 /// 
 /// Usage:
 /// 
-/// ```
+/// ```no_run
 /// use pcd_reader::PointCloud;
 /// let filename = "sample/sample_binary_compressed.pcd";
-/// let pcd = PointCloud::from_filename(filename);
-/// let x_data = pcd.get_data_f32("x");
-/// let y_data = pcd.get_data_f32("y");
-/// let z_data = pcd.get_data_f32("z");
-/// let intensity_data = pcd.get_data_u8("intensity");
-/// let ring_data = pcd.get_data_u8("ring");
+/// let pcd = PointCloud::from_filename(filename).unwrap();
+/// let x_data = pcd.get_data_f32("x").unwrap();
+/// let y_data = pcd.get_data_f32("y").unwrap();
+/// let z_data = pcd.get_data_f32("z").unwrap();
+/// let intensity_data = pcd.get_data_u8("intensity").unwrap();
+/// let ring_data = pcd.get_data_u8("ring").unwrap();
 /// assert_eq!(pcd.header.data_format, "binary_compressed");
 /// assert_eq!(pcd.header.num_points, 159602);
 /// assert_eq!(pcd.header.field_names, ["x", "y", "z", "intensity", "ring"]);
@@ -34,6 +70,92 @@ pub struct PointCloud {
     pub decompressed_buffer: Vec<u8>,
 }
 
+/// Per-field byte span within a single point: `size * count`.
+fn span_list(size_list: &[usize], count_list: &[usize]) -> Vec<usize> {
+    size_list
+        .iter()
+        .zip(count_list.iter())
+        .map(|(s, c)| s * c)
+        .collect()
+}
+
+/// Parse whitespace-separated `ascii` point data into the little-endian
+/// array-of-structs byte layout used by `binary`, so the downstream accessors
+/// only ever deal with two memory layouts instead of three. A field with
+/// `COUNT > 1` contributes that many consecutive tokens per line.
+fn ascii_to_binary(
+    text: &str,
+    type_list: &[String],
+    size_list: &[usize],
+    count_list: &[usize],
+    num_points: usize,
+) -> Result<Vec<u8>, PcdError> {
+    let stride: usize = span_list(size_list, count_list).iter().sum();
+    let total_tokens: usize = count_list.iter().sum();
+    let mut buffer = Vec::with_capacity(stride * num_points);
+    let mut rows: usize = 0;
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        rows += 1;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() != total_tokens {
+            return Err(PcdError::BadFieldCount {
+                expected: total_tokens,
+                got: tokens.len(),
+            });
+        }
+        let mut token_iter = tokens.iter();
+        for i in 0..type_list.len() {
+            for _ in 0..count_list[i] {
+                let token = token_iter.next().unwrap();
+                let malformed = || PcdError::MalformedDataLine {
+                    line: line.to_string(),
+                };
+                let mut bytes = vec![0u8; size_list[i]];
+                match (type_list[i].as_str(), size_list[i]) {
+                    ("F", 4) => {
+                        LittleEndian::write_f32(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("F", 8) => {
+                        LittleEndian::write_f64(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("U", 1) => bytes[0] = token.parse().map_err(|_| malformed())?,
+                    ("U", 2) => {
+                        LittleEndian::write_u16(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("U", 4) => {
+                        LittleEndian::write_u32(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("U", 8) => {
+                        LittleEndian::write_u64(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("I", 2) => {
+                        LittleEndian::write_i16(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("I", 4) => {
+                        LittleEndian::write_i32(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    ("I", 8) => {
+                        LittleEndian::write_i64(&mut bytes, token.parse().map_err(|_| malformed())?)
+                    }
+                    (t, s) => {
+                        return Err(PcdError::UnsupportedFieldType(format!("{}{}", t, s)))
+                    }
+                }
+                buffer.extend_from_slice(&bytes);
+            }
+        }
+    }
+    // The downstream accessors index `0..num_points`, so the data must carry
+    // exactly as many rows as the `POINTS` header advertised.
+    if rows != num_points {
+        return Err(PcdError::BadFieldCount {
+            expected: num_points,
+            got: rows,
+        });
+    }
+    Ok(buffer)
+}
+
 impl fmt::Debug for PointCloud {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PointCloud")
@@ -53,80 +175,163 @@ pub struct PointCloudHeader {
     pub field_names: Vec<String>,
     pub size_list: Vec<usize>,
     pub type_list: Vec<String>,
+    pub count_list: Vec<usize>,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A field's values decoded into the Rust type implied by its header
+/// `TYPE`/`SIZE`, letting callers read a heterogeneous cloud without knowing
+/// each field's exact width in advance.
+#[derive(Debug)]
+pub enum FieldValue {
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+    U64(Vec<u64>),
+    I16(Vec<i16>),
+    I32(Vec<i32>),
+    I64(Vec<i64>),
 }
 
 impl PointCloud {
-    pub fn from_filename(filename: &str) -> PointCloud {
-        let f = File::open(filename).expect("error reading pcd file.");
-        let mut reader = BufReader::new(&f);
+    pub fn from_filename(filename: &str) -> Result<PointCloud, PcdError> {
+        let f = File::open(filename)?;
+        PointCloud::from_reader(f)
+    }
+
+    pub fn from_reader<R: Read>(reader: R) -> Result<PointCloud, PcdError> {
+        let mut reader = BufReader::new(reader);
         let data_format: String;
         let mut num_points: usize = 0;
         let mut field_names = Vec::<String>::new();
         let mut size_list = Vec::<usize>::new();
         let mut type_list = Vec::<String>::new();
+        let mut count_list = Vec::<usize>::new();
+        let mut width: usize = 0;
+        let mut height: usize = 1;
 
         loop {
             let mut line = String::new();
-            let _ = reader
-                .read_line(&mut line)
-                .expect("failed to read a line from pcd header.");
-            line = line[..line.len() - 1].to_string();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                return Err(PcdError::NotAPcdFile);
+            }
+            line = line.trim_end_matches(['\r', '\n']).to_string();
+
+            let parse_usize_list = |words: &[String]| -> Result<Vec<usize>, PcdError> {
+                words
+                    .iter()
+                    .map(|s| {
+                        s.parse::<usize>()
+                            .map_err(|_| PcdError::MalformedHeaderLine { line: line.clone() })
+                    })
+                    .collect()
+            };
 
             if line.starts_with("#") {
             } else if line.starts_with("VERSION") {
             } else if line.starts_with("FIELDS") {
                 let words: Vec<String> = line.split(" ").map(|s| s.to_string()).collect();
-                let words = &words[1..];
-                field_names = words.to_vec();
+                field_names = words[1..].to_vec();
             } else if line.starts_with("SIZE") {
                 let words: Vec<String> = line.split(" ").map(|s| s.to_string()).collect();
-                let words = &words[1..];
-                size_list = words.iter().map(|s|s.parse::<usize>().expect("entry POINTS in header has wrong format: its second term is not integer.")).collect();
+                size_list = parse_usize_list(&words[1..])?;
             } else if line.starts_with("TYPE") {
                 let words: Vec<String> = line.split(" ").map(|s| s.to_string()).collect();
-                let words = &words[1..];
-                type_list = words.to_vec();
+                type_list = words[1..].to_vec();
             } else if line.starts_with("COUNT") {
+                let words: Vec<String> = line.split(" ").map(|s| s.to_string()).collect();
+                count_list = parse_usize_list(&words[1..])?;
             } else if line.starts_with("WIDTH") {
+                let words: Vec<&str> = line.split(" ").collect();
+                if words.len() != 2 {
+                    return Err(PcdError::MalformedHeaderLine { line: line.clone() });
+                }
+                width = words[1]
+                    .parse::<usize>()
+                    .map_err(|_| PcdError::MalformedHeaderLine { line: line.clone() })?;
             } else if line.starts_with("HEIGHT") {
+                let words: Vec<&str> = line.split(" ").collect();
+                if words.len() != 2 {
+                    return Err(PcdError::MalformedHeaderLine { line: line.clone() });
+                }
+                height = words[1]
+                    .parse::<usize>()
+                    .map_err(|_| PcdError::MalformedHeaderLine { line: line.clone() })?;
             } else if line.starts_with("VIEWPOINT") {
             } else if line.starts_with("POINTS") {
                 let words: Vec<&str> = line.split(" ").collect();
                 if words.len() != 2 {
-                    panic!("entry POINTS in header has wrong format: it consists of other than 2 words")
+                    return Err(PcdError::MalformedHeaderLine { line: line.clone() });
                 }
-                num_points = words[1].parse::<usize>().expect(
-                    "entry POINTS in header has wrong format: its second term is not integer.",
-                );
+                num_points = words[1]
+                    .parse::<usize>()
+                    .map_err(|_| PcdError::MalformedHeaderLine { line: line.clone() })?;
             } else if line.starts_with("DATA") {
                 let words: Vec<&str> = line.split(" ").collect();
                 if words.len() != 2 {
-                    panic!(
-                        "entry DATA in header has wrong format: it consists of other than 2 words"
-                    )
+                    return Err(PcdError::MalformedHeaderLine { line: line.clone() });
                 }
-                if "binary_compressed" == words[1] {
-                    data_format = words[1].to_string();
-                } else {
-                    panic!("currently only supporting binary_compressed format.");
+                match words[1] {
+                    "binary_compressed" | "binary" | "ascii" => data_format = words[1].to_string(),
+                    other => return Err(PcdError::UnsupportedDataFormat(other.to_string())),
                 }
                 break;
             } else {
-                panic!("unknown header entry");
+                return Err(PcdError::MalformedHeaderLine { line: line.clone() });
             }
         }
 
-        let mut u32_size_buffer = vec![0u8; 4];
-        let _ = reader.read_exact(&mut u32_size_buffer);
-        let compressed_size = LittleEndian::read_u32(&u32_size_buffer) as usize;
-        let _ = reader.read_exact(&mut u32_size_buffer);
-        let uncompressed_size = LittleEndian::read_u32(&u32_size_buffer) as usize;
-
-        let mut compressed_size_buffer = vec![0u8; compressed_size];
-        let _ = reader.read_exact(&mut compressed_size_buffer);
-        let decompressed_buffer = lzf::decompress(&compressed_size_buffer, uncompressed_size)
-            .expect("error decompressing binary_comprressed pcd data.");
-        PointCloud {
+        // `COUNT` is optional; when absent every field carries a single element.
+        if count_list.is_empty() {
+            count_list = vec![1; field_names.len()];
+        }
+
+        // Organized (image-like) clouds carry a `width * height` grid; the two
+        // dimensions must agree with the advertised point count.
+        if height > 1 && width * height != num_points {
+            return Err(PcdError::GridSizeMismatch {
+                width,
+                height,
+                num_points,
+            });
+        }
+
+        // `binary_compressed` stores the cloud struct-of-arrays (all x, then all y, ...),
+        // while plain `binary` and `ascii` are array-of-structs (each point's fields
+        // interleaved). The accessors pick their indexing strategy from `data_format`;
+        // here we only need to materialize the raw point bytes into `decompressed_buffer`,
+        // normalizing `ascii` into the same little-endian array-of-structs layout as `binary`.
+        let decompressed_buffer = match data_format.as_str() {
+            "binary_compressed" => {
+                let mut u32_size_buffer = vec![0u8; 4];
+                reader.read_exact(&mut u32_size_buffer)?;
+                let compressed_size = LittleEndian::read_u32(&u32_size_buffer) as usize;
+                reader.read_exact(&mut u32_size_buffer)?;
+                let uncompressed_size = LittleEndian::read_u32(&u32_size_buffer) as usize;
+
+                let mut compressed_size_buffer = vec![0u8; compressed_size];
+                reader.read_exact(&mut compressed_size_buffer)?;
+                lzf::decompress(&compressed_size_buffer, uncompressed_size)
+                    .map_err(|_| PcdError::Decompress)?
+            }
+            "binary" => {
+                let stride: usize = span_list(&size_list, &count_list).iter().sum();
+                let mut buffer = vec![0u8; stride * num_points];
+                reader.read_exact(&mut buffer)?;
+                buffer
+            }
+            "ascii" => {
+                let mut text = String::new();
+                reader.read_to_string(&mut text)?;
+                ascii_to_binary(&text, &type_list, &size_list, &count_list, num_points)?
+            }
+            _ => unreachable!(),
+        };
+        Ok(PointCloud {
             decompressed_buffer,
             header: PointCloudHeader {
                 data_format,
@@ -134,26 +339,59 @@ impl PointCloud {
                 field_names,
                 size_list,
                 type_list,
+                count_list,
+                width,
+                height,
             },
-        }
+        })
     }
 
-    fn get_data_offset(&self, fieldname: &str, type_string: &str, item_size: usize) -> usize {
+    fn get_data_offset(
+        &self,
+        fieldname: &str,
+        type_string: &str,
+        item_size: usize,
+    ) -> Result<usize, PcdError> {
         let mut data_offset: usize = 0;
         for (i, fname) in self.header.field_names.iter().enumerate() {
             if fname == fieldname {
                 if self.header.type_list[i] != type_string || self.header.size_list[i] != item_size
                 {
-                    panic!(
-                        "required fieldname is not a type of {}{}",
-                        type_string, item_size
-                    )
+                    return Err(PcdError::FieldTypeMismatch {
+                        field: fieldname.to_string(),
+                        expected_type: type_string.to_string(),
+                        expected_size: item_size,
+                    });
                 }
                 break;
             }
-            data_offset += self.header.size_list[i];
+            data_offset += self.header.size_list[i] * self.header.count_list[i];
         }
-        data_offset
+        Ok(data_offset)
+    }
+
+    /// Decode a field into a [`FieldValue`] by dispatching on its header
+    /// `TYPE`/`SIZE`, so callers need not know the field's exact Rust type.
+    pub fn get_field(&self, fieldname: &str) -> Result<FieldValue, PcdError> {
+        let index = self
+            .header
+            .field_names
+            .iter()
+            .position(|f| f == fieldname)
+            .ok_or_else(|| PcdError::UnknownField(fieldname.to_string()))?;
+        let value = match (self.header.type_list[index].as_str(), self.header.size_list[index]) {
+            ("F", 4) => FieldValue::F32(self.get_data_f32(fieldname)?),
+            ("F", 8) => FieldValue::F64(self.get_data_f64(fieldname)?),
+            ("U", 1) => FieldValue::U8(self.get_data_u8(fieldname)?),
+            ("U", 2) => FieldValue::U16(self.get_data_u16(fieldname)?),
+            ("U", 4) => FieldValue::U32(self.get_data_u32(fieldname)?),
+            ("U", 8) => FieldValue::U64(self.get_data_u64(fieldname)?),
+            ("I", 2) => FieldValue::I16(self.get_data_i16(fieldname)?),
+            ("I", 4) => FieldValue::I32(self.get_data_i32(fieldname)?),
+            ("I", 8) => FieldValue::I64(self.get_data_i64(fieldname)?),
+            (t, s) => return Err(PcdError::UnsupportedFieldType(format!("{}{}", t, s))),
+        };
+        Ok(value)
     }
 
     fn read_data<T>(
@@ -163,19 +401,93 @@ impl PointCloud {
         item_size: usize,
         read_buffer_fn: fn(&[u8], &mut [T]),
         data_buffer: &mut Vec<T>,
-    ) {
+    ) -> Result<(), PcdError> {
         if !self.header.field_names.contains(&fieldname.to_string()) {
-            panic!("pointcloud does not contain required fieldname");
+            return Err(PcdError::UnknownField(fieldname.to_string()));
         }
-        let data_offset = self.get_data_offset(fieldname, type_string, item_size);
-        read_buffer_fn(
-            &self.decompressed_buffer[data_offset * self.header.num_points
-                ..(data_offset + item_size) * self.header.num_points],
-            data_buffer,
-        );
+        let data_offset = self.get_data_offset(fieldname, type_string, item_size)?;
+        let num_points = self.header.num_points;
+        if self.header.data_format == "binary_compressed" {
+            // Struct-of-arrays: the field occupies one contiguous run of the buffer.
+            read_buffer_fn(
+                &self.decompressed_buffer
+                    [data_offset * num_points..(data_offset + item_size) * num_points],
+                data_buffer,
+            );
+        } else {
+            // Array-of-structs: gather the field out of each point's stride first.
+            let stride: usize = span_list(&self.header.size_list, &self.header.count_list)
+                .iter()
+                .sum();
+            let mut gathered = vec![0u8; item_size * num_points];
+            for p in 0..num_points {
+                let src = p * stride + data_offset;
+                let dst = p * item_size;
+                gathered[dst..dst + item_size]
+                    .copy_from_slice(&self.decompressed_buffer[src..src + item_size]);
+            }
+            read_buffer_fn(&gathered, data_buffer);
+        }
+        Ok(())
     }
 
-    pub fn get_data_f32(&self, fieldname: &str) -> Vec<f32> {
+    /// Byte offset of element `e` of a field (whose intra-point byte offset is
+    /// `field_offset`) for point `p`, honoring the file's struct-of-arrays
+    /// (`binary_compressed`) or array-of-structs (`binary`/`ascii`) layout.
+    fn element_start(&self, field_offset: usize, item_size: usize, p: usize, e: usize) -> usize {
+        if self.header.data_format == "binary_compressed" {
+            (field_offset + e * item_size) * self.header.num_points + p * item_size
+        } else {
+            let stride: usize = span_list(&self.header.size_list, &self.header.count_list)
+                .iter()
+                .sum();
+            p * stride + field_offset + e * item_size
+        }
+    }
+
+    /// Borrow the cloud as a lazy iterator of [`Point`] views, computing each
+    /// point's bytes on demand instead of allocating a vector per field.
+    pub fn points(&self) -> Points<'_> {
+        Points {
+            cloud: self,
+            index: 0,
+        }
+    }
+
+    fn read_data_multi<T>(
+        &self,
+        fieldname: &str,
+        type_string: &str,
+        item_size: usize,
+        decode_one: fn(&[u8]) -> T,
+    ) -> Result<Vec<Vec<T>>, PcdError> {
+        if !self.header.field_names.contains(&fieldname.to_string()) {
+            return Err(PcdError::UnknownField(fieldname.to_string()));
+        }
+        let field_offset = self.get_data_offset(fieldname, type_string, item_size)?;
+        let index = self
+            .header
+            .field_names
+            .iter()
+            .position(|f| f == fieldname)
+            .unwrap();
+        let count = self.header.count_list[index];
+        let num_points = self.header.num_points;
+        let mut result = Vec::with_capacity(num_points);
+        for p in 0..num_points {
+            let mut point = Vec::with_capacity(count);
+            for e in 0..count {
+                let start = self.element_start(field_offset, item_size, p, e);
+                point.push(decode_one(
+                    &self.decompressed_buffer[start..start + item_size],
+                ));
+            }
+            result.push(point);
+        }
+        Ok(result)
+    }
+
+    pub fn get_data_f32(&self, fieldname: &str) -> Result<Vec<f32>, PcdError> {
         let mut data_buffer = vec![0.0; self.header.num_points];
         self.read_data::<f32>(
             fieldname,
@@ -183,11 +495,64 @@ impl PointCloud {
             4,
             LittleEndian::read_f32_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
+    }
+
+    pub fn get_data_f32_multi(&self, fieldname: &str) -> Result<Vec<Vec<f32>>, PcdError> {
+        self.read_data_multi::<f32>(fieldname, "F", 4, LittleEndian::read_f32)
+    }
+
+    /// Reshape a field of an organized cloud into `height` rows of `width`
+    /// values each (row-major), preserving the grid structure carried by
+    /// depth-camera PCDs. Unorganized clouds (`HEIGHT == 1`) come back as a
+    /// single row.
+    pub fn get_data_f32_organized(&self, fieldname: &str) -> Result<Vec<Vec<f32>>, PcdError> {
+        let flat = self.get_data_f32(fieldname)?;
+        if self.header.height <= 1 {
+            return Ok(vec![flat]);
+        }
+        let width = if self.header.width == 0 {
+            flat.len().max(1)
+        } else {
+            self.header.width
+        };
+        Ok(flat.chunks(width).map(|row| row.to_vec()).collect())
+    }
+
+    pub fn get_data_f64_multi(&self, fieldname: &str) -> Result<Vec<Vec<f64>>, PcdError> {
+        self.read_data_multi::<f64>(fieldname, "F", 8, LittleEndian::read_f64)
+    }
+
+    pub fn get_data_u8_multi(&self, fieldname: &str) -> Result<Vec<Vec<u8>>, PcdError> {
+        self.read_data_multi::<u8>(fieldname, "U", 1, |b| b[0])
+    }
+
+    pub fn get_data_u16_multi(&self, fieldname: &str) -> Result<Vec<Vec<u16>>, PcdError> {
+        self.read_data_multi::<u16>(fieldname, "U", 2, LittleEndian::read_u16)
     }
 
-    pub fn get_data_f64(&self, fieldname: &str) -> Vec<f64> {
+    pub fn get_data_u32_multi(&self, fieldname: &str) -> Result<Vec<Vec<u32>>, PcdError> {
+        self.read_data_multi::<u32>(fieldname, "U", 4, LittleEndian::read_u32)
+    }
+
+    pub fn get_data_u64_multi(&self, fieldname: &str) -> Result<Vec<Vec<u64>>, PcdError> {
+        self.read_data_multi::<u64>(fieldname, "U", 8, LittleEndian::read_u64)
+    }
+
+    pub fn get_data_i16_multi(&self, fieldname: &str) -> Result<Vec<Vec<i16>>, PcdError> {
+        self.read_data_multi::<i16>(fieldname, "I", 2, LittleEndian::read_i16)
+    }
+
+    pub fn get_data_i32_multi(&self, fieldname: &str) -> Result<Vec<Vec<i32>>, PcdError> {
+        self.read_data_multi::<i32>(fieldname, "I", 4, LittleEndian::read_i32)
+    }
+
+    pub fn get_data_i64_multi(&self, fieldname: &str) -> Result<Vec<Vec<i64>>, PcdError> {
+        self.read_data_multi::<i64>(fieldname, "I", 8, LittleEndian::read_i64)
+    }
+
+    pub fn get_data_f64(&self, fieldname: &str) -> Result<Vec<f64>, PcdError> {
         let mut data_buffer = vec![0.0; self.header.num_points];
         self.read_data::<f64>(
             fieldname,
@@ -195,20 +560,20 @@ impl PointCloud {
             8,
             LittleEndian::read_f64_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_u8(&self, fieldname: &str) -> Vec<u8> {
+    pub fn get_data_u8(&self, fieldname: &str) -> Result<Vec<u8>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         fn copy_u8_into(source: &[u8], target: &mut [u8]) {
             target[..].clone_from_slice(source);
         }
-        self.read_data::<u8>(fieldname, "U", 1, copy_u8_into, &mut data_buffer);
-        data_buffer
+        self.read_data::<u8>(fieldname, "U", 1, copy_u8_into, &mut data_buffer)?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_u16(&self, fieldname: &str) -> Vec<u16> {
+    pub fn get_data_u16(&self, fieldname: &str) -> Result<Vec<u16>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<u16>(
             fieldname,
@@ -216,11 +581,11 @@ impl PointCloud {
             2,
             LittleEndian::read_u16_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_u32(&self, fieldname: &str) -> Vec<u32> {
+    pub fn get_data_u32(&self, fieldname: &str) -> Result<Vec<u32>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<u32>(
             fieldname,
@@ -228,11 +593,11 @@ impl PointCloud {
             4,
             LittleEndian::read_u32_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_u64(&self, fieldname: &str) -> Vec<u64> {
+    pub fn get_data_u64(&self, fieldname: &str) -> Result<Vec<u64>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<u64>(
             fieldname,
@@ -240,8 +605,8 @@ impl PointCloud {
             8,
             LittleEndian::read_u64_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
     // pub fn get_data_i8(&self, fieldname: &str) -> Vec<i8> {
@@ -256,7 +621,7 @@ impl PointCloud {
     //     data_buffer
     // }
 
-    pub fn get_data_i16(&self, fieldname: &str) -> Vec<i16> {
+    pub fn get_data_i16(&self, fieldname: &str) -> Result<Vec<i16>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<i16>(
             fieldname,
@@ -264,11 +629,11 @@ impl PointCloud {
             2,
             LittleEndian::read_i16_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_i32(&self, fieldname: &str) -> Vec<i32> {
+    pub fn get_data_i32(&self, fieldname: &str) -> Result<Vec<i32>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<i32>(
             fieldname,
@@ -276,11 +641,11 @@ impl PointCloud {
             4,
             LittleEndian::read_i32_into,
             &mut data_buffer,
-        );
-        data_buffer
+        )?;
+        Ok(data_buffer)
     }
 
-    pub fn get_data_i64(&self, fieldname: &str) -> Vec<i64> {
+    pub fn get_data_i64(&self, fieldname: &str) -> Result<Vec<i64>, PcdError> {
         let mut data_buffer = vec![0; self.header.num_points];
         self.read_data::<i64>(
             fieldname,
@@ -288,8 +653,253 @@ impl PointCloud {
             8,
             LittleEndian::read_i64_into,
             &mut data_buffer,
+        )?;
+        Ok(data_buffer)
+    }
+}
+
+/// Lazy iterator over the points of a [`PointCloud`], yielding one [`Point`]
+/// view at a time without materializing any per-field vectors.
+pub struct Points<'a> {
+    cloud: &'a PointCloud,
+    index: usize,
+}
+
+impl<'a> Iterator for Points<'a> {
+    type Item = Point<'a>;
+
+    fn next(&mut self) -> Option<Point<'a>> {
+        if self.index < self.cloud.header.num_points {
+            let point = Point {
+                cloud: self.cloud,
+                index: self.index,
+            };
+            self.index += 1;
+            Some(point)
+        } else {
+            None
+        }
+    }
+}
+
+/// A lightweight view onto a single point, holding a reference back to the
+/// cloud's `decompressed_buffer` plus the point index. The typed accessors
+/// slice out that one point's bytes on demand, respecting whichever SoA/AoS
+/// layout the source file used.
+pub struct Point<'a> {
+    cloud: &'a PointCloud,
+    index: usize,
+}
+
+impl<'a> Point<'a> {
+    fn read<T>(
+        &self,
+        fieldname: &str,
+        type_string: &str,
+        item_size: usize,
+        decode: fn(&[u8]) -> T,
+    ) -> Result<T, PcdError> {
+        if !self.cloud.header.field_names.contains(&fieldname.to_string()) {
+            return Err(PcdError::UnknownField(fieldname.to_string()));
+        }
+        let field_offset = self
+            .cloud
+            .get_data_offset(fieldname, type_string, item_size)?;
+        let start = self.cloud.element_start(field_offset, item_size, self.index, 0);
+        Ok(decode(&self.cloud.decompressed_buffer[start..start + item_size]))
+    }
+
+    pub fn get_f32(&self, fieldname: &str) -> Result<f32, PcdError> {
+        self.read(fieldname, "F", 4, LittleEndian::read_f32)
+    }
+
+    pub fn get_f64(&self, fieldname: &str) -> Result<f64, PcdError> {
+        self.read(fieldname, "F", 8, LittleEndian::read_f64)
+    }
+
+    pub fn get_u8(&self, fieldname: &str) -> Result<u8, PcdError> {
+        self.read(fieldname, "U", 1, |b| b[0])
+    }
+
+    pub fn get_u16(&self, fieldname: &str) -> Result<u16, PcdError> {
+        self.read(fieldname, "U", 2, LittleEndian::read_u16)
+    }
+
+    pub fn get_u32(&self, fieldname: &str) -> Result<u32, PcdError> {
+        self.read(fieldname, "U", 4, LittleEndian::read_u32)
+    }
+
+    pub fn get_u64(&self, fieldname: &str) -> Result<u64, PcdError> {
+        self.read(fieldname, "U", 8, LittleEndian::read_u64)
+    }
+
+    pub fn get_i16(&self, fieldname: &str) -> Result<i16, PcdError> {
+        self.read(fieldname, "I", 2, LittleEndian::read_i16)
+    }
+
+    pub fn get_i32(&self, fieldname: &str) -> Result<i32, PcdError> {
+        self.read(fieldname, "I", 4, LittleEndian::read_i32)
+    }
+
+    pub fn get_i64(&self, fieldname: &str) -> Result<i64, PcdError> {
+        self.read(fieldname, "I", 8, LittleEndian::read_i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ASCII_PCD: &[u8] = b"# .PCD v0.7\n\
+VERSION 0.7\n\
+FIELDS x y z intensity\n\
+SIZE 4 4 4 1\n\
+TYPE F F F U\n\
+COUNT 1 1 1 1\n\
+WIDTH 2\n\
+HEIGHT 1\n\
+POINTS 2\n\
+DATA ascii\n\
+1.0 2.0 3.0 10\n\
+4.0 5.0 6.0 20\n";
+
+    /// Build the equivalent `binary` (array-of-structs) PCD for the same two
+    /// points as [`ASCII_PCD`], so the two layouts can be cross-checked.
+    fn binary_pcd() -> Vec<u8> {
+        let mut buf = b"# .PCD v0.7\n\
+VERSION 0.7\n\
+FIELDS x y z intensity\n\
+SIZE 4 4 4 1\n\
+TYPE F F F U\n\
+COUNT 1 1 1 1\n\
+WIDTH 2\n\
+HEIGHT 1\n\
+POINTS 2\n\
+DATA binary\n"
+            .to_vec();
+        for (x, y, z, i) in [(1.0f32, 2.0f32, 3.0f32, 10u8), (4.0, 5.0, 6.0, 20)] {
+            let mut f = [0u8; 4];
+            LittleEndian::write_f32(&mut f, x);
+            buf.extend_from_slice(&f);
+            LittleEndian::write_f32(&mut f, y);
+            buf.extend_from_slice(&f);
+            LittleEndian::write_f32(&mut f, z);
+            buf.extend_from_slice(&f);
+            buf.push(i);
+        }
+        buf
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let pcd = PointCloud::from_reader(ASCII_PCD).unwrap();
+        assert_eq!(pcd.header.num_points, 2);
+        assert_eq!(pcd.get_data_f32("x").unwrap(), [1.0, 4.0]);
+        assert_eq!(pcd.get_data_f32("y").unwrap(), [2.0, 5.0]);
+        assert_eq!(pcd.get_data_f32("z").unwrap(), [3.0, 6.0]);
+        assert_eq!(pcd.get_data_u8("intensity").unwrap(), [10, 20]);
+    }
+
+    #[test]
+    fn binary_matches_ascii() {
+        let ascii = PointCloud::from_reader(ASCII_PCD).unwrap();
+        let binary = PointCloud::from_reader(binary_pcd().as_slice()).unwrap();
+        for field in ["x", "y", "z"] {
+            assert_eq!(
+                ascii.get_data_f32(field).unwrap(),
+                binary.get_data_f32(field).unwrap()
+            );
+        }
+        assert_eq!(
+            ascii.get_data_u8("intensity").unwrap(),
+            binary.get_data_u8("intensity").unwrap()
         );
-        data_buffer
+    }
+
+    #[test]
+    fn points_iterator_matches_columnar() {
+        let pcd = PointCloud::from_reader(ASCII_PCD).unwrap();
+        let xs: Vec<f32> = pcd.points().map(|p| p.get_f32("x").unwrap()).collect();
+        let is: Vec<u8> = pcd.points().map(|p| p.get_u8("intensity").unwrap()).collect();
+        assert_eq!(xs, pcd.get_data_f32("x").unwrap());
+        assert_eq!(is, pcd.get_data_u8("intensity").unwrap());
+    }
+
+    #[test]
+    fn get_field_dispatches_on_header() {
+        let pcd = PointCloud::from_reader(ASCII_PCD).unwrap();
+        match pcd.get_field("x").unwrap() {
+            FieldValue::F32(v) => assert_eq!(v, [1.0, 4.0]),
+            other => panic!("expected F32, got {:?}", other),
+        }
+        match pcd.get_field("intensity").unwrap() {
+            FieldValue::U8(v) => assert_eq!(v, [10, 20]),
+            other => panic!("expected U8, got {:?}", other),
+        }
+        assert!(matches!(
+            pcd.get_field("missing"),
+            Err(PcdError::UnknownField(_))
+        ));
+    }
+
+    #[test]
+    fn count_spans_multi_element_field() {
+        let pcd = PointCloud::from_reader(
+            b"FIELDS normal\n\
+SIZE 4\n\
+TYPE F\n\
+COUNT 3\n\
+WIDTH 2\n\
+HEIGHT 1\n\
+POINTS 2\n\
+DATA ascii\n\
+1.0 2.0 3.0\n\
+4.0 5.0 6.0\n"
+                .as_slice(),
+        )
+        .unwrap();
+        let normals = pcd.get_data_f32_multi("normal").unwrap();
+        assert_eq!(normals, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn organized_reshape_into_rows() {
+        let pcd = PointCloud::from_reader(
+            b"FIELDS x\n\
+SIZE 4\n\
+TYPE F\n\
+COUNT 1\n\
+WIDTH 2\n\
+HEIGHT 2\n\
+POINTS 4\n\
+DATA ascii\n\
+1.0\n\
+2.0\n\
+3.0\n\
+4.0\n"
+                .as_slice(),
+        )
+        .unwrap();
+        let rows = pcd.get_data_f32_organized("x").unwrap();
+        assert_eq!(rows, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    }
+
+    #[test]
+    fn ascii_row_count_mismatch_errors() {
+        let short = b"FIELDS x\n\
+SIZE 4\n\
+TYPE F\n\
+COUNT 1\n\
+WIDTH 3\n\
+HEIGHT 1\n\
+POINTS 3\n\
+DATA ascii\n\
+1.0\n\
+2.0\n";
+        assert!(matches!(
+            PointCloud::from_reader(short.as_slice()),
+            Err(PcdError::BadFieldCount { .. })
+        ));
     }
 }
 